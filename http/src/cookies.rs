@@ -0,0 +1,160 @@
+use crate::requests::Request;
+use crate::response::Response;
+
+/**
+ * Attributes for a `Set-Cookie` header, configured via
+ * `Response::with_cookie_options`. All attributes are optional; omitted
+ * ones are simply left off the serialized header.
+ *
+ * # Example
+ * ```
+ * let options = CookieOptions::new()
+ *     .path("/")
+ *     .max_age(3600)
+ *     .http_only(true)
+ *     .same_site(SameSite::Lax);
+ *
+ * Response::text("welcome").with_cookie_options("session", &token, &options)
+ * ```
+ */
+pub struct CookieOptions {
+    path: Option<String>,
+    domain: Option<String>,
+    max_age: Option<u64>,
+    secure: bool,
+    http_only: bool,
+    same_site: Option<SameSite>,
+}
+
+/// Value for a cookie's `SameSite` attribute.
+pub enum SameSite {
+    Strict,
+    Lax,
+    None,
+}
+
+impl SameSite {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SameSite::Strict => "Strict",
+            SameSite::Lax => "Lax",
+            SameSite::None => "None",
+        }
+    }
+}
+
+impl CookieOptions {
+    /// Creates an empty set of cookie attributes (a session cookie scoped
+    /// to the current path, with no `Secure`/`HttpOnly`/`SameSite` set).
+    pub fn new() -> Self {
+        Self {
+            path: None,
+            domain: None,
+            max_age: None,
+            secure: false,
+            http_only: false,
+            same_site: None,
+        }
+    }
+
+    /// Sets the `Path` attribute.
+    pub fn path(mut self, path: &str) -> Self {
+        self.path = Some(path.to_string());
+        self
+    }
+
+    /// Sets the `Domain` attribute.
+    pub fn domain(mut self, domain: &str) -> Self {
+        self.domain = Some(domain.to_string());
+        self
+    }
+
+    /// Sets the `Max-Age` attribute, in seconds.
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Sets the `Secure` attribute.
+    pub fn secure(mut self, secure: bool) -> Self {
+        self.secure = secure;
+        self
+    }
+
+    /// Sets the `HttpOnly` attribute.
+    pub fn http_only(mut self, http_only: bool) -> Self {
+        self.http_only = http_only;
+        self
+    }
+
+    /// Sets the `SameSite` attribute.
+    pub fn same_site(mut self, same_site: SameSite) -> Self {
+        self.same_site = Some(same_site);
+        self
+    }
+}
+
+impl Default for CookieOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Request {
+    /// Returns the value of a cookie sent in the request's `Cookie` header,
+    /// e.g. `req.cookie("session")`. Returns `None` if there's no `Cookie`
+    /// header or the named cookie isn't present in it.
+    pub fn cookie(&self, name: &str) -> Option<&str> {
+        self.header("Cookie")?.split(';').find_map(|pair| {
+            let (key, value) = pair.trim().split_once('=')?;
+            (key == name).then_some(value)
+        })
+    }
+}
+
+impl Response {
+    /**
+     * Appends a `Set-Cookie` header for `name=value`, scoped to the current
+     * path by default. For Domain, Max-Age, Secure, HttpOnly, or SameSite
+     * attributes, use `with_cookie_options`.
+     *
+     * Unlike `with_header`, this never overwrites an existing `Set-Cookie`
+     * header — responses may set more than one cookie.
+     *
+     * # Example
+     * ```
+     * Response::text("welcome").with_cookie("session", &token)
+     * ```
+     */
+    pub fn with_cookie(self, name: &str, value: &str) -> Self {
+        self.with_cookie_options(name, value, &CookieOptions::default())
+    }
+
+    /// Appends a `Set-Cookie` header for `name=value` with the given
+    /// attributes. See `with_cookie` for the common case.
+    pub fn with_cookie_options(mut self, name: &str, value: &str, options: &CookieOptions) -> Self {
+        let mut cookie = format!("{}={}", name, value);
+
+        if let Some(path) = &options.path {
+            cookie.push_str(&format!("; Path={}", path));
+        }
+        if let Some(domain) = &options.domain {
+            cookie.push_str(&format!("; Domain={}", domain));
+        }
+        if let Some(max_age) = options.max_age {
+            cookie.push_str(&format!("; Max-Age={}", max_age));
+        }
+        if options.secure {
+            cookie.push_str("; Secure");
+        }
+        if options.http_only {
+            cookie.push_str("; HttpOnly");
+        }
+        if let Some(same_site) = &options.same_site {
+            cookie.push_str(&format!("; SameSite={}", same_site.as_str()));
+        }
+
+        self.headers.push(("Set-Cookie".to_string(), cookie));
+        self
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashSet;
+
+use crate::{
+    middleware::Layer,
+    requests::{Method, Request},
+    response::Response,
+};
+
+/// Which origins a `Cors` layer will accept.
+enum AllowedOrigins {
+    /// Any origin is accepted. Echoes the literal `*` wildcard unless
+    /// credentials are enabled, in which case the exact requesting origin
+    /// is echoed back instead (a browser requirement for credentialed
+    /// requests).
+    Any,
+    /// Only origins in this set are accepted; the single matching origin is
+    /// echoed back rather than the whole list.
+    List(HashSet<String>),
+}
+
+/**
+ * CORS middleware layer: answers `OPTIONS` preflight requests and annotates
+ * other responses with `Access-Control-Allow-*` headers so browser-based
+ * clients on allowed origins can call the API.
+ *
+ * # Example
+ * ```
+ * let cors = Cors::new()
+ *     .allow_origin("https://example.com")
+ *     .allow_origin("https://admin.example.com")
+ *     .allow_methods(["GET", "POST"])
+ *     .allow_headers(["Content-Type", "Authorization"]);
+ *
+ * router.layer(cors);
+ * ```
+ */
+pub struct Cors {
+    allowed_origins: AllowedOrigins,
+    allowed_methods: Vec<String>,
+    allowed_headers: Vec<String>,
+    allow_credentials: bool,
+    max_age: Option<u64>,
+}
+
+impl Cors {
+    /**
+     * Creates a `Cors` layer with no allowed origins (reject by default),
+     * a permissive default method list, and `Content-Type` as the only
+     * allowed header. Call `allow_origin` or `allow_any_origin` to permit
+     * requests.
+     */
+    pub fn new() -> Self {
+        Self {
+            allowed_origins: AllowedOrigins::List(HashSet::new()),
+            allowed_methods: ["GET", "POST", "PUT", "PATCH", "DELETE", "OPTIONS"]
+                .into_iter()
+                .map(String::from)
+                .collect(),
+            allowed_headers: vec!["Content-Type".to_string()],
+            allow_credentials: false,
+            max_age: None,
+        }
+    }
+
+    /// Adds a single origin to the allow-list, e.g. `https://example.com`.
+    pub fn allow_origin(mut self, origin: &str) -> Self {
+        if let AllowedOrigins::List(origins) = &mut self.allowed_origins {
+            origins.insert(origin.to_string());
+        }
+        self
+    }
+
+    /// Accepts every origin, responding with a literal `*` wildcard unless
+    /// `allow_credentials(true)` is also set, in which case the requesting
+    /// origin is echoed back instead (per the Fetch spec, a wildcard cannot
+    /// be combined with credentialed requests).
+    pub fn allow_any_origin(mut self) -> Self {
+        self.allowed_origins = AllowedOrigins::Any;
+        self
+    }
+
+    /// Sets the methods advertised in `Access-Control-Allow-Methods` on
+    /// preflight responses.
+    pub fn allow_methods<I, S>(mut self, methods: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_methods = methods.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the headers advertised in `Access-Control-Allow-Headers` on
+    /// preflight responses.
+    pub fn allow_headers<I, S>(mut self, headers: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.allowed_headers = headers.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets whether `Access-Control-Allow-Credentials: true` is sent.
+    pub fn allow_credentials(mut self, allow: bool) -> Self {
+        self.allow_credentials = allow;
+        self
+    }
+
+    /// Sets the `Access-Control-Max-Age` (in seconds) sent on preflight
+    /// responses, letting browsers cache the preflight result.
+    pub fn max_age(mut self, seconds: u64) -> Self {
+        self.max_age = Some(seconds);
+        self
+    }
+
+    /// Returns the value to echo back in `Access-Control-Allow-Origin` for a
+    /// given request `Origin`, or `None` if that origin isn't allowed.
+    fn allowed_origin_for(&self, origin: &str) -> Option<String> {
+        match &self.allowed_origins {
+            AllowedOrigins::Any if !self.allow_credentials => Some("*".to_string()),
+            AllowedOrigins::Any => Some(origin.to_string()),
+            AllowedOrigins::List(origins) => origins.contains(origin).then(|| origin.to_string()),
+        }
+    }
+
+    /// Adds the shared `Access-Control-Allow-*` headers that apply to both
+    /// preflight and regular responses, if the request's origin is allowed.
+    fn apply_origin_headers(&self, response: Response, origin: Option<&str>) -> Response {
+        let Some(origin) = origin else {
+            return response;
+        };
+        let Some(allowed_origin) = self.allowed_origin_for(origin) else {
+            return response;
+        };
+
+        let mut response = response.with_header("Access-Control-Allow-Origin", &allowed_origin);
+        if self.is_origin_dependent() {
+            // The allowed origin echoed above depends on this request's
+            // `Origin`, so a shared cache must key on it too — otherwise it
+            // could serve one origin's allow-listed response to another.
+            response = response.with_header("Vary", "Origin");
+        }
+        if self.allow_credentials {
+            response = response.with_header("Access-Control-Allow-Credentials", "true");
+        }
+        response
+    }
+
+    /// Whether `allowed_origin_for` echoes back the request's own `Origin`
+    /// rather than a value fixed for all requests (the uncredentialed `Any`
+    /// case, which always answers `*`).
+    fn is_origin_dependent(&self) -> bool {
+        match self.allowed_origins {
+            AllowedOrigins::Any => self.allow_credentials,
+            AllowedOrigins::List(_) => true,
+        }
+    }
+
+    /// Builds the `204 No Content` response used to answer a preflight
+    /// `OPTIONS` request.
+    fn preflight_response(&self, origin: Option<&str>) -> Response {
+        let mut response = self
+            .apply_origin_headers(Response::new(204, ""), origin)
+            .with_header("Access-Control-Allow-Methods", &self.allowed_methods.join(", "))
+            .with_header("Access-Control-Allow-Headers", &self.allowed_headers.join(", "));
+
+        if let Some(max_age) = self.max_age {
+            response = response.with_header("Access-Control-Max-Age", &max_age.to_string());
+        }
+
+        response
+    }
+}
+
+impl Default for Cors {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Layer for Cors {
+    fn handle(&self, req: Request, next: &dyn Fn(Request) -> Response) -> Response {
+        let origin = req.header("Origin").map(str::to_string);
+
+        if req.method == Method::OPTIONS {
+            return self.preflight_response(origin.as_deref());
+        }
+
+        let response = next(req);
+        self.apply_origin_headers(response, origin.as_deref())
+    }
+}
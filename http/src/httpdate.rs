@@ -0,0 +1,118 @@
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/**
+ * Minimal RFC 1123 ("IMF-fixdate") HTTP date formatting and parsing, used
+ * for the `Last-Modified` / `If-Modified-Since` headers on static file
+ * responses. Deliberately hand-rolled rather than pulling in a date crate —
+ * the calendar math is small and self-contained.
+ */
+const DAY_NAMES: [&str; 7] = ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"];
+const MONTH_NAMES: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Formats a `SystemTime` as an HTTP date, e.g. `Tue, 15 Nov 1994 08:12:31 GMT`.
+pub fn format(time: SystemTime) -> String {
+    let secs = time.duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+    let days = (secs / 86_400) as i64;
+    let time_of_day = secs % 86_400;
+
+    let (year, month, day) = civil_from_days(days);
+    let weekday = DAY_NAMES[(days + 4).rem_euclid(7) as usize]; // 1970-01-01 was a Thursday
+    let hour = time_of_day / 3600;
+    let minute = (time_of_day % 3600) / 60;
+    let second = time_of_day % 60;
+
+    format!(
+        "{}, {:02} {} {} {:02}:{:02}:{:02} GMT",
+        weekday,
+        day,
+        MONTH_NAMES[(month - 1) as usize],
+        year,
+        hour,
+        minute,
+        second
+    )
+}
+
+/// Parses an HTTP date in the common RFC 1123 form, e.g.
+/// `Tue, 15 Nov 1994 08:12:31 GMT`. Returns `None` on any other format.
+pub fn parse(value: &str) -> Option<SystemTime> {
+    let (_, rest) = value.trim().split_once(", ")?;
+    let mut parts = rest.split_whitespace();
+
+    let day: u32 = parts.next()?.parse().ok()?;
+    let month_name = parts.next()?;
+    let month = MONTH_NAMES.iter().position(|name| *name == month_name)? as u32 + 1;
+    let year: i64 = parts.next()?.parse().ok()?;
+
+    let mut time_fields = parts.next()?.split(':');
+    let hour: u64 = time_fields.next()?.parse().ok()?;
+    let minute: u64 = time_fields.next()?.parse().ok()?;
+    let second: u64 = time_fields.next()?.parse().ok()?;
+
+    let days = days_from_civil(year, month, day);
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second;
+
+    Some(UNIX_EPOCH + Duration::from_secs(secs))
+}
+
+/// Howard Hinnant's `civil_from_days`: converts a day count since the Unix
+/// epoch into a (year, month, day) civil (Gregorian) date.
+fn civil_from_days(z: i64) -> (i64, u32, u32) {
+    let z = z + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097); // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365; // [0, 399]
+    let y = yoe + era * 400;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32; // [1, 31]
+    let month = (if mp < 10 { mp + 3 } else { mp - 9 }) as u32; // [1, 12]
+    let year = if month <= 2 { y + 1 } else { y };
+    (year, month, day)
+}
+
+/// Inverse of `civil_from_days`: the number of days since the Unix epoch for
+/// a given (year, month, day) civil date.
+fn days_from_civil(year: i64, month: u32, day: u32) -> u64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400); // [0, 399]
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) as i64 + 2) / 5 + day as i64 - 1; // [0, 365]
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy; // [0, 146096]
+    (era * 146_097 + doe - 719_468) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format, parse};
+    use std::time::{Duration, UNIX_EPOCH};
+
+    #[test]
+    fn formats_known_epoch_seconds() {
+        // 2024-01-15 08:12:31 UTC, a Monday.
+        let time = UNIX_EPOCH + Duration::from_secs(1_705_306_351);
+        assert_eq!(format(time), "Mon, 15 Jan 2024 08:12:31 GMT");
+    }
+
+    #[test]
+    fn round_trips_format_and_parse() {
+        let original = UNIX_EPOCH + Duration::from_secs(1_705_306_351);
+        let formatted = format(original);
+        let parsed = parse(&formatted).expect("should parse a string we just formatted");
+        assert_eq!(parsed, original);
+    }
+
+    #[test]
+    fn parses_reference_rfc1123_date() {
+        let parsed = parse("Tue, 15 Nov 1994 08:12:31 GMT").unwrap();
+        assert_eq!(parsed, UNIX_EPOCH + Duration::from_secs(784_887_151));
+    }
+
+    #[test]
+    fn rejects_malformed_input() {
+        assert!(parse("not a date").is_none());
+        assert!(parse("Tue, 15 Nov 1994").is_none());
+    }
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+use std::fmt;
+
+use serde::de::DeserializeOwned;
+
+use crate::requests::Request;
+use crate::response::Response;
+
+/// Default body-size limit (in bytes) accepted by `Request::json`.
+const DEFAULT_MAX_JSON_LEN: usize = 1024 * 1024;
+
+/**
+ * Configuration for `Request::json_with_config`: how large a JSON body may
+ * be, and which `Content-Type` values are accepted besides the default
+ * `application/json`.
+ *
+ * # Example
+ * ```
+ * let config = JsonConfig::new()
+ *     .max_len(64 * 1024)
+ *     .allow_content_type("application/vnd.api+json");
+ *
+ * let body: CreateUser = req.json_with_config(&config)?;
+ * ```
+ */
+pub struct JsonConfig {
+    max_len: usize,
+    allowed_content_types: Vec<String>,
+}
+
+impl JsonConfig {
+    /// Creates a config accepting only `application/json`, up to 1 MiB.
+    pub fn new() -> Self {
+        Self {
+            max_len: DEFAULT_MAX_JSON_LEN,
+            allowed_content_types: vec!["application/json".to_string()],
+        }
+    }
+
+    /// Sets the maximum accepted body size, in bytes.
+    pub fn max_len(mut self, limit: usize) -> Self {
+        self.max_len = limit;
+        self
+    }
+
+    /// Accepts an additional `Content-Type` (beyond `application/json`) as JSON.
+    pub fn allow_content_type(mut self, content_type: &str) -> Self {
+        self.allowed_content_types.push(content_type.to_string());
+        self
+    }
+}
+
+impl Default for JsonConfig {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Errors returned by `Request::json` / `Request::json_with_config`.
+#[derive(Debug)]
+pub enum JsonError {
+    /// The request's `Content-Type` wasn't one of the accepted JSON types.
+    UnsupportedContentType(String),
+    /// The body exceeded the configured size limit.
+    PayloadTooLarge,
+    /// The body wasn't valid JSON for the target type.
+    Malformed(serde_json::Error),
+}
+
+impl fmt::Display for JsonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            JsonError::UnsupportedContentType(content_type) => {
+                write!(f, "unsupported content type: {}", content_type)
+            }
+            JsonError::PayloadTooLarge => write!(f, "JSON body too large"),
+            JsonError::Malformed(err) => write!(f, "malformed JSON body: {}", err),
+        }
+    }
+}
+
+impl Error for JsonError {}
+
+impl JsonError {
+    /// Converts this error into the `400 Bad Request` response a handler
+    /// should typically send back to the client.
+    pub fn into_response(self) -> Response {
+        Response::new(400, self.to_string())
+    }
+}
+
+impl Request {
+    /// Parses the request body as JSON, requiring a `Content-Type` of
+    /// `application/json` and a body no larger than 1 MiB. For custom
+    /// content types or size limits, use `json_with_config`.
+    pub fn json<T: DeserializeOwned>(&self) -> Result<T, JsonError> {
+        self.json_with_config(&JsonConfig::default())
+    }
+
+    /// Parses the request body as JSON per the given `JsonConfig`.
+    pub fn json_with_config<T: DeserializeOwned>(&self, config: &JsonConfig) -> Result<T, JsonError> {
+        let content_type = self.header("Content-Type").unwrap_or("");
+        let base_type = content_type.split(';').next().unwrap_or("").trim();
+
+        if !config
+            .allowed_content_types
+            .iter()
+            .any(|allowed| allowed.eq_ignore_ascii_case(base_type))
+        {
+            return Err(JsonError::UnsupportedContentType(content_type.to_string()));
+        }
+
+        if self.body.len() > config.max_len {
+            return Err(JsonError::PayloadTooLarge);
+        }
+
+        serde_json::from_slice(&self.body).map_err(JsonError::Malformed)
+    }
+}
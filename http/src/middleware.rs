@@ -0,0 +1,28 @@
+use crate::{requests::Request, response::Response};
+
+/**
+ * Hook for cross-cutting concerns (CORS, logging, auth, ...) that sit between
+ * the router and its route handlers.
+ *
+ * Layers registered on a `Router` run in registration order, outermost
+ * first; each layer decides whether, and with what request, to call `next`
+ * to continue the chain, and may inspect or rewrite the resulting response
+ * before returning it.
+ *
+ * # Example
+ * ```
+ * struct Logger;
+ *
+ * impl Layer for Logger {
+ *     fn handle(&self, req: Request, next: &dyn Fn(Request) -> Response) -> Response {
+ *         let path = req.path.clone();
+ *         let response = next(req);
+ *         println!("{} -> {}", path, response.status_code);
+ *         response
+ *     }
+ * }
+ * ```
+ */
+pub trait Layer: Send + Sync {
+    fn handle(&self, req: Request, next: &dyn Fn(Request) -> Response) -> Response;
+}
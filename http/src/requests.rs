@@ -1,4 +1,8 @@
 use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read};
+use std::net::TcpStream;
+use std::time::Duration;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Method {
@@ -30,38 +34,380 @@ impl Method {
 pub struct Request {
     pub method: Method,
     pub path: String,
+    /// HTTP version from the request line, e.g. `HTTP/1.1`. Used to pick the
+    /// default `Connection` behavior (HTTP/1.0 closes by default, HTTP/1.1
+    /// keeps the connection alive by default) when the client doesn't send
+    /// an explicit `Connection` header.
+    pub version: String,
+    pub headers: HashMap<String, String>,
     pub body: Vec<u8>,
+    /// Named path parameters captured by the router while matching this
+    /// request against a route (e.g. `:id` in `/users/:id`). Empty until
+    /// the router has dispatched the request.
+    pub params: HashMap<String, String>,
+}
+
+/// Errors that can occur while reading a request off a connection.
+///
+/// Distinct from a parse failure on an in-memory buffer: these carry enough
+/// information for `handle_client` to decide whether to close the
+/// connection quietly, or answer with a `400`, `408` or `413`.
+#[derive(Debug)]
+pub enum RequestReadError {
+    /// The client closed the connection before sending any data.
+    ConnectionClosed,
+    /// No new request arrived on a keep-alive connection before the idle
+    /// keep-alive timeout elapsed; the connection should simply be closed.
+    IdleTimeout,
+    /// A request started arriving but wasn't fully received before the
+    /// slow-request deadline; callers should answer with `408`.
+    SlowRequestTimeout,
+    /// The request line, headers, or chunked framing could not be parsed.
+    Malformed,
+    /// The request body exceeded the configured size limit.
+    PayloadTooLarge,
+    Io(io::Error),
+}
+
+impl fmt::Display for RequestReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RequestReadError::ConnectionClosed => write!(f, "connection closed"),
+            RequestReadError::IdleTimeout => write!(f, "idle keep-alive timeout"),
+            RequestReadError::SlowRequestTimeout => write!(f, "slow-request timeout"),
+            RequestReadError::Malformed => write!(f, "malformed request"),
+            RequestReadError::PayloadTooLarge => write!(f, "payload too large"),
+            RequestReadError::Io(err) => write!(f, "io error: {}", err),
+        }
+    }
+}
+
+impl From<io::Error> for RequestReadError {
+    fn from(err: io::Error) -> Self {
+        RequestReadError::Io(err)
+    }
 }
 
 impl Request {
-    pub fn from_raw(raw: &str) -> Option<Self> {
-        let mut lines = raw.lines();
+    /// Reads a single request off `reader`, honoring `Content-Length` and
+    /// chunked transfer framing so bodies larger than any one `read()` call
+    /// (and binary bodies) survive intact.
+    ///
+    /// `max_body_len` bounds how many body bytes will be buffered in memory;
+    /// exceeding it yields `RequestReadError::PayloadTooLarge` before the
+    /// full body is read off the wire. `keep_alive` is the read timeout
+    /// while the first line of a new request is awaited on an idle
+    /// connection, and `client_timeout` is the read timeout for every read
+    /// after that (the rest of the head and the body). Both bound a single
+    /// read, not the total time a request takes to arrive.
+    pub fn from_stream(
+        reader: &mut BufReader<TcpStream>,
+        max_body_len: usize,
+        keep_alive: Duration,
+        client_timeout: Duration,
+    ) -> Result<Self, RequestReadError> {
+        let head = read_head(reader, keep_alive, client_timeout)?;
+        let (method, path, version, headers) =
+            Self::parse_head(&head).ok_or(RequestReadError::Malformed)?;
 
-        let request_line = lines.next()?;
+        // Transfer-Encoding wins over Content-Length when both are present
+        // (RFC 7230 §3.3.3) — trusting Content-Length here would let a
+        // client smuggle a second request inside what looks like this
+        // one's body. Only `chunked` can be decoded here, so any other
+        // transfer coding is rejected as malformed.
+        let is_chunked = match headers.get("transfer-encoding") {
+            Some(value) if value.eq_ignore_ascii_case("chunked") => true,
+            Some(_) => return Err(RequestReadError::Malformed),
+            None => false,
+        };
 
+        let body = if is_chunked {
+            read_chunked_body(reader, max_body_len)?
+        } else if let Some(length) = headers.get("content-length") {
+            let length: usize = length.trim().parse().map_err(|_| RequestReadError::Malformed)?;
+            if length > max_body_len {
+                return Err(RequestReadError::PayloadTooLarge);
+            }
+            let mut body = vec![0u8; length];
+            reader.read_exact(&mut body).map_err(classify_timeout)?;
+            body
+        } else {
+            Vec::new()
+        };
+
+        Ok(Self::new(method, path, version, headers, body))
+    }
+
+    /// Parses a complete request (head + body) from an already fully-read
+    /// buffer. Unlike `from_stream`, this does not honor `Content-Length` or
+    /// chunked framing — any bytes following the head are treated as the
+    /// body verbatim.
+    pub fn from_raw(raw: &[u8]) -> Option<Self> {
+        let terminator = find_subsequence(raw, b"\r\n\r\n")?;
+        let (method, path, version, headers) = Self::parse_head(&raw[..terminator + 4])?;
+        let body = raw[terminator + 4..].to_vec();
+
+        Some(Self::new(method, path, version, headers, body))
+    }
+
+    /// Parses the request line and headers out of a head buffer (everything
+    /// up to and including the blank line that terminates it).
+    ///
+    /// Header names are lowercased on the way in, since HTTP field names are
+    /// case-insensitive (RFC 7230 §3.2) — `header()` lowercases its lookup
+    /// key to match.
+    fn parse_head(head: &[u8]) -> Option<(Method, String, String, HashMap<String, String>)> {
+        let head = std::str::from_utf8(head).ok()?;
+        let mut lines = head.lines();
+
+        let request_line = lines.next()?;
         let mut parts = request_line.split_whitespace();
         let method_str = parts.next()?;
         let path = parts.next()?.to_string();
-
+        let version = parts.next().unwrap_or("HTTP/1.1").to_string();
         let method = Method::from_str(method_str)?;
 
         let mut headers = HashMap::new();
-
-        for line in &mut lines {
+        for line in lines {
             if line.is_empty() {
                 break;
             }
 
-            if let Some((key, value)) = line.split_once(":") {
-                headers.insert(key.trim().to_string(), value.trim().to_string());
+            if let Some((key, value)) = line.split_once(':') {
+                headers.insert(key.trim().to_ascii_lowercase(), value.trim().to_string());
             }
         }
 
-        let body = lines
-            .collect::<Vec<&str>>()
-            .join("\n")
-            .into_bytes();
+        Some((method, path, version, headers))
+    }
+
+    fn new(
+        method: Method,
+        path: String,
+        version: String,
+        headers: HashMap<String, String>,
+        body: Vec<u8>,
+    ) -> Self {
+        Self { method, path, version, headers, body, params: HashMap::new() }
+    }
+
+    /// Returns the value of a named path parameter bound by the router,
+    /// e.g. `req.param("id")` for a route registered as `/users/:id`.
+    pub fn param(&self, name: &str) -> Option<&str> {
+        self.params.get(name).map(String::as_str)
+    }
+
+    /// Returns a request header by name. Lookups are case-insensitive, per
+    /// HTTP field name semantics (RFC 7230 §3.2): `header("content-type")`
+    /// and `header("Content-Type")` are equivalent.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers.get(&name.to_ascii_lowercase()).map(String::as_str)
+    }
+}
+
+/// Reads from `reader` line by line until the blank `\r\n` that terminates
+/// the request head, returning the head bytes (request line + headers +
+/// terminating blank line).
+///
+/// While the first line of a new request is read, the read timeout is
+/// `keep_alive`: if it expires with nothing received, the connection is idle
+/// and simply closed; if part of the line has arrived, it is a slow request.
+/// Once the first line is in, the timeout switches to the (usually shorter)
+/// `client_timeout` for the rest of the head, so a client that stalls
+/// mid-head gets a `408` rather than hanging the thread indefinitely. Each
+/// timeout bounds a single read, not the whole head: a client that sends
+/// some bytes before every timeout expires is not cut off.
+fn read_head(
+    reader: &mut BufReader<TcpStream>,
+    keep_alive: Duration,
+    client_timeout: Duration,
+) -> Result<Vec<u8>, RequestReadError> {
+    let mut head = Vec::new();
+    reader.get_ref().set_read_timeout(Some(keep_alive))?;
+
+    loop {
+        let before = head.len();
+        let read_result = reader.read_until(b'\n', &mut head);
+
+        let bytes_read = match read_result {
+            Ok(bytes_read) => bytes_read,
+            Err(err) if is_timeout(&err) => {
+                return if head.is_empty() {
+                    Err(RequestReadError::IdleTimeout)
+                } else {
+                    Err(RequestReadError::SlowRequestTimeout)
+                };
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        if bytes_read == 0 {
+            return if head.is_empty() {
+                Err(RequestReadError::ConnectionClosed)
+            } else {
+                Err(RequestReadError::Malformed)
+            };
+        }
+
+        if head.len() == bytes_read {
+            // The request line just arrived; switch to the slow-request
+            // timeout for the rest of the head.
+            reader.get_ref().set_read_timeout(Some(client_timeout))?;
+        }
+
+        let line = &head[before..];
+        if line == b"\r\n" || line == b"\n" {
+            return Ok(head);
+        }
+    }
+}
+
+/// Decodes a chunked-transfer body: repeatedly reads a hex chunk-size line,
+/// then that many data bytes followed by a trailing CRLF, stopping at the
+/// terminating zero-sized chunk.
+fn read_chunked_body(
+    reader: &mut BufReader<TcpStream>,
+    max_body_len: usize,
+) -> Result<Vec<u8>, RequestReadError> {
+    let mut body = Vec::new();
+
+    loop {
+        let mut size_line = String::new();
+        reader.read_line(&mut size_line).map_err(classify_timeout)?;
+
+        let size_str = size_line.trim().split(';').next().unwrap_or("").trim();
+        if size_str.is_empty() {
+            return Err(RequestReadError::Malformed);
+        }
+
+        let chunk_size =
+            usize::from_str_radix(size_str, 16).map_err(|_| RequestReadError::Malformed)?;
+
+        if chunk_size == 0 {
+            // Consume any trailer fields and the blank line that ends the
+            // chunked stream before handing the body back.
+            loop {
+                let mut trailer = String::new();
+                let bytes_read = reader.read_line(&mut trailer).map_err(classify_timeout)?;
+                if bytes_read == 0 || trailer.trim().is_empty() {
+                    return Ok(body);
+                }
+            }
+        }
+
+        // Compare rather than add: a client can declare a chunk size near
+        // `usize::MAX`, and `body.len() + chunk_size` would wrap past the
+        // limit instead of exceeding it, letting the oversized chunk
+        // through to the `vec![0u8; chunk_size]` allocation below.
+        if chunk_size > max_body_len.saturating_sub(body.len()) {
+            return Err(RequestReadError::PayloadTooLarge);
+        }
+
+        let mut chunk = vec![0u8; chunk_size];
+        reader.read_exact(&mut chunk).map_err(classify_timeout)?;
+        body.extend_from_slice(&chunk);
+
+        let mut crlf = [0u8; 2];
+        reader.read_exact(&mut crlf).map_err(classify_timeout)?;
+    }
+}
+
+fn is_timeout(err: &io::Error) -> bool {
+    matches!(err.kind(), io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut)
+}
+
+/// Maps a read error encountered mid-request (body or chunked framing) to
+/// `SlowRequestTimeout` when it was a read-deadline timeout, or `Io`
+/// otherwise.
+fn classify_timeout(err: io::Error) -> RequestReadError {
+    if is_timeout(&err) {
+        RequestReadError::SlowRequestTimeout
+    } else {
+        RequestReadError::Io(err)
+    }
+}
+
+fn find_subsequence(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_chunked_body, RequestReadError};
+    use std::io::{BufReader, Write};
+    use std::net::{TcpListener, TcpStream};
+    use std::thread;
+
+    /// Connects a loopback `TcpStream` pair for feeding bytes into
+    /// `read_chunked_body`, which is concretized over `BufReader<TcpStream>`
+    /// rather than a generic reader.
+    fn connected_pair() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let client = thread::spawn(move || TcpStream::connect(addr).unwrap());
+        let (server, _) = listener.accept().unwrap();
+        (server, client.join().unwrap())
+    }
+
+    #[test]
+    fn decodes_a_multi_chunk_body() {
+        let (server, mut client) = connected_pair();
+        let mut reader = BufReader::new(server);
+
+        client
+            .write_all(b"5\r\nhello\r\n6\r\n world\r\n0\r\n\r\n")
+            .unwrap();
+
+        let body = read_chunked_body(&mut reader, 1024).unwrap();
+        assert_eq!(body, b"hello world");
+    }
+
+    #[test]
+    fn decodes_an_empty_chunked_body() {
+        let (server, mut client) = connected_pair();
+        let mut reader = BufReader::new(server);
+
+        client.write_all(b"0\r\n\r\n").unwrap();
+
+        let body = read_chunked_body(&mut reader, 1024).unwrap();
+        assert!(body.is_empty());
+    }
+
+    #[test]
+    fn rejects_a_chunk_size_that_isnt_hex() {
+        let (server, mut client) = connected_pair();
+        let mut reader = BufReader::new(server);
+
+        client.write_all(b"not-hex\r\nhello\r\n0\r\n\r\n").unwrap();
+
+        let result = read_chunked_body(&mut reader, 1024);
+        assert!(matches!(result, Err(RequestReadError::Malformed)));
+    }
+
+    #[test]
+    fn rejects_a_body_exceeding_max_len() {
+        let (server, mut client) = connected_pair();
+        let mut reader = BufReader::new(server);
+
+        client.write_all(b"5\r\nhello\r\n0\r\n\r\n").unwrap();
+
+        let result = read_chunked_body(&mut reader, 3);
+        assert!(matches!(result, Err(RequestReadError::PayloadTooLarge)));
+    }
+
+    #[test]
+    fn rejects_a_huge_declared_chunk_size_without_overflowing() {
+        let (server, mut client) = connected_pair();
+        let mut reader = BufReader::new(server);
+
+        // A small chunk first makes `body.len()` nonzero, then a
+        // near-`usize::MAX` chunk size would wrap the old `body.len() +
+        // chunk_size` addition past `max_body_len` instead of exceeding it.
+        client.write_all(b"1\r\nx\r\nffffffffffffffff\r\n").unwrap();
 
-        Some(Self { method, path, body })
+        let result = read_chunked_body(&mut reader, 1024);
+        assert!(matches!(result, Err(RequestReadError::PayloadTooLarge)));
     }
 }
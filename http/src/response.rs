@@ -1,4 +1,11 @@
-use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::UNIX_EPOCH;
+
+use serde::Serialize;
+
+use crate::httpdate;
 
 /**
  * HTTP response builder with fluent API for constructing responses.
@@ -6,12 +13,17 @@ use std::collections::HashMap;
  * Provides convenience methods for common response types and chainable
  * header manipulation. Automatically sets sensible defaults like Content-Length
  * and Connection headers.
+ *
+ * Headers are stored as an ordered list rather than a map, since a response
+ * can legitimately carry more than one header with the same name (most
+ * notably `Set-Cookie`, one per cookie) and a map would silently collapse
+ * them.
  */
 pub struct Response {
     pub body: Vec<u8>,
     pub status_code: u16,
     pub status_text: String,
-    pub headers: HashMap<String, String>,
+    pub headers: Vec<(String, String)>,
 }
 
 impl Response {
@@ -29,27 +41,19 @@ impl Response {
      * ```
      */
     pub fn new(status_code: u16, body: impl Into<Vec<u8>>) -> Self {
-        let status_text = match status_code {
-            200 => "OK",
-            201 => "Created",
-            204 => "No Content",
-            400 => "Bad Request",
-            401 => "Unauthorized",
-            403 => "Forbidden",
-            404 => "Not Found",
-            500 => "Internal Server Error",
-            _ => "OK",
-        }
-        .to_string();
+        let status_text = Self::status_text_for(status_code).to_string();
 
         // Capacity should be increased as the project grows
-        let mut headers = HashMap::with_capacity(16);
+        let mut headers = Vec::with_capacity(16);
 
         let body_bytes = body.into();
 
-        headers.insert("Content-Type".to_string(), "text/plain; charset=utf-8".to_string());
-        headers.insert("Connection".to_string(), "close".to_string());
-        headers.insert("Content-Length".to_string(), body_bytes.len().to_string());
+        headers.push(("Content-Type".to_string(), "text/plain; charset=utf-8".to_string()));
+        headers.push(("Connection".to_string(), "close".to_string()));
+
+        if !is_bodyless(status_code) {
+            headers.push(("Content-Length".to_string(), body_bytes.len().to_string()));
+        }
 
         Response {
             status_code,
@@ -59,6 +63,44 @@ impl Response {
         }
     }
 
+    /**
+     * Returns the standard reason phrase for an HTTP status code, e.g.
+     * `404` -> `"Not Found"`. Falls back to `"Unknown Status"` for codes
+     * outside the standard set, rather than guessing.
+     */
+    pub fn status_text_for(status_code: u16) -> &'static str {
+        match status_code {
+            100 => "Continue",
+            101 => "Switching Protocols",
+            102 => "Processing",
+            200 => "OK",
+            201 => "Created",
+            202 => "Accepted",
+            204 => "No Content",
+            206 => "Partial Content",
+            301 => "Moved Permanently",
+            302 => "Found",
+            303 => "See Other",
+            304 => "Not Modified",
+            307 => "Temporary Redirect",
+            308 => "Permanent Redirect",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            408 => "Request Timeout",
+            409 => "Conflict",
+            413 => "Payload Too Large",
+            422 => "Unprocessable Entity",
+            429 => "Too Many Requests",
+            500 => "Internal Server Error",
+            502 => "Bad Gateway",
+            503 => "Service Unavailable",
+            _ => "Unknown Status",
+        }
+    }
+
     /**
      * Creates a 200 OK response with plain text content.
      *
@@ -90,9 +132,61 @@ impl Response {
         Self::new(404, b"Not Found".to_vec())
     }
 
+    /**
+     * Serializes `value` to JSON and builds a 200 OK response with
+     * `Content-Type: application/json; charset=utf-8`.
+     *
+     * A serialization failure (e.g. a map with non-string keys) falls back
+     * to a `500 Internal Server Error` response carrying the error message,
+     * since there's no body left to report it as a normal JSON error.
+     *
+     * # Example
+     * ```
+     * router.get("/health", |_req| Response::json(&serde_json::json!({ "ok": true })));
+     * ```
+     */
+    pub fn json<T: Serialize>(value: &T) -> Self {
+        match serde_json::to_vec(value) {
+            Ok(body) => {
+                Self::new(200, body).with_header("Content-Type", "application/json; charset=utf-8")
+            }
+            Err(err) => Self::new(500, format!("Failed to serialize JSON response: {}", err)),
+        }
+    }
+
+    /**
+     * Reads a file from disk and builds a 200 OK response with a
+     * `Content-Type` inferred from the file extension, `Content-Length`, an
+     * `ETag` derived from the file's size and modification time, and a
+     * `Last-Modified` header.
+     *
+     * Returns an `io::Error` if the file cannot be read. Callers serving
+     * untrusted paths should pair this with their own existence and
+     * traversal checks (see `Router::static_dir`).
+     *
+     * # Example
+     * ```
+     * router.get("/logo.png", |_req| Response::file("assets/logo.png").unwrap());
+     * ```
+     */
+    pub fn file(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let metadata = fs::metadata(path)?;
+        let body = fs::read(path)?;
+
+        Ok(Self::new(200, body)
+            .with_header("Content-Type", content_type_for(path))
+            .with_header("ETag", &etag_for(&metadata))
+            .with_header("Last-Modified", &httpdate::format(metadata.modified()?)))
+    }
+
     /**
      * Adds or overwrites a header. Returns self for method chaining.
      *
+     * If a header with this name already exists it is replaced; to add a
+     * header that can repeat (like `Set-Cookie`), push onto `self.headers`
+     * directly instead (see `Response::with_cookie`).
+     *
      * # Example
      * ```
      * Response::text("OK")
@@ -101,8 +195,8 @@ impl Response {
      * ```
      */
     pub fn with_header(mut self, key: &str, value: &str) -> Self {
-        self.headers
-            .insert(key.to_string(), value.to_string());
+        self.headers.retain(|(existing, _)| existing != key);
+        self.headers.push((key.to_string(), value.to_string()));
         self
     }
 
@@ -121,7 +215,9 @@ impl Response {
         response.push_str("\r\n");
 
         let mut bytes = response.into_bytes();
-        bytes.extend_from_slice(&self.body);
+        if !is_bodyless(self.status_code) {
+            bytes.extend_from_slice(&self.body);
+        }
         bytes
     }
 
@@ -148,3 +244,47 @@ impl Response {
         response.into_bytes()
     }
 }
+
+/// Infers a `Content-Type` from a file's extension, defaulting to
+/// `application/octet-stream` for anything unrecognized.
+fn content_type_for(path: &Path) -> &'static str {
+    let extension = path
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .unwrap_or("")
+        .to_ascii_lowercase();
+
+    match extension.as_str() {
+        "html" | "htm" => "text/html; charset=utf-8",
+        "css" => "text/css; charset=utf-8",
+        "js" | "mjs" => "application/javascript; charset=utf-8",
+        "json" => "application/json; charset=utf-8",
+        "txt" => "text/plain; charset=utf-8",
+        "png" => "image/png",
+        "jpg" | "jpeg" => "image/jpeg",
+        "gif" => "image/gif",
+        "svg" => "image/svg+xml",
+        "ico" => "image/x-icon",
+        "pdf" => "application/pdf",
+        "wasm" => "application/wasm",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Whether responses with this status code must not carry a body, per HTTP
+/// semantics (1xx informational, `204 No Content`, `304 Not Modified`).
+fn is_bodyless(status_code: u16) -> bool {
+    matches!(status_code, 100..=199 | 204 | 304)
+}
+
+/// Derives a simple strong ETag from a file's size and modification time.
+pub(crate) fn etag_for(metadata: &fs::Metadata) -> String {
+    let mtime = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    format!("\"{:x}-{:x}\"", metadata.len(), mtime)
+}
@@ -1,6 +1,11 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
 use std::sync::Arc;
 
+use regex::Regex;
+
 use crate::{
+    middleware::Layer,
     requests::{Method, Request},
     response::Response,
 };
@@ -10,15 +15,121 @@ use crate::{
  */
 pub type Handler = Arc<dyn Fn(Request) -> Response + Send + Sync + 'static>;
 
+/**
+ * A single parsed piece of a registered route path.
+ *
+ * Built once at registration time so that dispatch only has to walk the
+ * request path's segments against this list rather than re-parsing the
+ * route on every request.
+ */
+enum Segment {
+    /// A literal path segment that must match exactly, e.g. `users`.
+    Literal(String),
+    /// A named segment, e.g. `:id`, optionally constrained by an inline
+    /// regex such as `:id<\d+>` that the captured value must fully match.
+    Param(String, Option<Regex>),
+    /// A trailing `*name` segment that captures the rest of the path,
+    /// including any further `/`-separated segments.
+    Wildcard(String),
+}
+
+impl Segment {
+    fn parse_all(path: &str) -> Vec<Segment> {
+        path.split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(Segment::parse)
+            .collect()
+    }
+
+    /// # Panics
+    /// Panics if a `:name<pattern>` segment's inline constraint isn't a
+    /// valid regex. This is a route-registration bug (a typo'd pattern), not
+    /// something a request can trigger, so it's surfaced immediately rather
+    /// than silently falling back to an unconstrained (match-anything) param
+    /// — the same "fail fast at startup" treatment `Server::run` gives an
+    /// unbindable address.
+    fn parse(raw: &str) -> Segment {
+        if let Some(name) = raw.strip_prefix('*') {
+            return Segment::Wildcard(name.to_string());
+        }
+
+        let Some(rest) = raw.strip_prefix(':') else {
+            return Segment::Literal(raw.to_string());
+        };
+
+        match rest.find('<') {
+            Some(open) if rest.ends_with('>') => {
+                let name = rest[..open].to_string();
+                let pattern = &rest[open + 1..rest.len() - 1];
+                let anchored = format!("^(?:{})$", pattern);
+                let regex = Regex::new(&anchored)
+                    .unwrap_or_else(|err| panic!("invalid route constraint `{}`: {}", pattern, err));
+                Segment::Param(name, Some(regex))
+            }
+            _ => Segment::Param(rest.to_string(), None),
+        }
+    }
+}
+
 /**
  * Represents a single route with its HTTP method, path, and handler function.
  */
 pub struct Route {
     pub method: Method,
     pub path: String,
+    segments: Vec<Segment>,
     pub handler: Handler,
 }
 
+impl Route {
+    /**
+     * Matches a request path against this route's segments, returning the
+     * bound path parameters on success.
+     *
+     * Literal segments must match exactly, `:name` segments bind any single
+     * non-empty segment (optionally validated against an inline regex
+     * constraint), and a trailing `*name` segment captures the remainder of
+     * the path. Segment counts must line up unless a wildcard is present.
+     */
+    fn matches(&self, path: &str) -> Option<HashMap<String, String>> {
+        let mut request_segments = path.split('/').filter(|segment| !segment.is_empty());
+        let mut params = HashMap::new();
+
+        for segment in &self.segments {
+            match segment {
+                Segment::Wildcard(name) => {
+                    let rest: Vec<&str> = request_segments.by_ref().collect();
+                    if rest.is_empty() {
+                        return None;
+                    }
+                    params.insert(name.clone(), rest.join("/"));
+                    return Some(params);
+                }
+                Segment::Literal(literal) => {
+                    if request_segments.next()? != literal {
+                        return None;
+                    }
+                }
+                Segment::Param(name, constraint) => {
+                    let value = request_segments.next()?;
+                    if let Some(pattern) = constraint {
+                        if !pattern.is_match(value) {
+                            return None;
+                        }
+                    }
+                    params.insert(name.clone(), value.to_string());
+                }
+            }
+        }
+
+        if request_segments.next().is_some() {
+            return None;
+        }
+
+        Some(params)
+    }
+}
+
 /**
  * HTTP router that registers routes and dispatches incoming requests to handlers.
  *
@@ -40,6 +151,7 @@ pub struct Route {
  */
 pub struct Router {
     routes: Vec<Route>,
+    layers: Vec<Arc<dyn Layer>>,
 }
 
 impl Router {
@@ -47,7 +159,24 @@ impl Router {
      * Creates a new empty router.
      */
     pub fn new() -> Self {
-        Self { routes: Vec::new() }
+        Self { routes: Vec::new(), layers: Vec::new() }
+    }
+
+    /**
+     * Registers a middleware layer that wraps every request dispatched
+     * through this router, e.g. a `Cors` layer for browser-facing APIs.
+     *
+     * Layers run in registration order, outermost first: the first layer
+     * registered sees the request first and the response last.
+     *
+     * # Example
+     * ```
+     * router.layer(Cors::new().allow_origin("https://example.com"));
+     * ```
+     */
+    pub fn layer(&mut self, layer: impl Layer + 'static) -> &mut Self {
+        self.layers.push(Arc::new(layer));
+        self
     }
 
     /**
@@ -56,8 +185,12 @@ impl Router {
      * Returns `&mut Self` to enable method chaining.
      */
     pub fn add_route(&mut self, method: Method, path: &str, handler: Handler) -> &mut Self {
-        self.routes
-            .push(Route { method, path: path.to_string(), handler });
+        self.routes.push(Route {
+            method,
+            segments: Segment::parse_all(path),
+            path: path.to_string(),
+            handler,
+        });
         self
     }
 
@@ -198,19 +331,54 @@ impl Router {
     }
 
     /**
-     * Dispatches an incoming request to the appropriate handler.
+     * Registers a GET route that serves files out of `dir` under `prefix`,
+     * e.g. `static_dir("/static", "./public")` serves `./public/app.js` as
+     * `/static/app.js`. Conditional requests (`If-None-Match` /
+     * `If-Modified-Since`) are answered with `304 Not Modified`, and any
+     * path escaping `dir` (after resolving `..` and symlinks) is rejected
+     * with `403 Forbidden`.
      *
-     * Currently performs exact matching on method + path.
-     * Returns `Response::not_found()` if no matching route is found.
+     * # Example
+     * ```
+     * router.static_dir("/static", "./public");
+     * ```
+     */
+    pub fn static_dir(&mut self, prefix: &str, dir: impl Into<PathBuf>) -> &mut Self {
+        let route = format!("{}/*path", prefix.trim_end_matches('/'));
+        self.get(&route, crate::static_files::serve_dir(dir))
+    }
+
+    /**
+     * Dispatches an incoming request through the registered middleware
+     * layers (outermost first) and on to the matching route handler.
      *
-     * # Future
-     * Will support path parameters (e.g., `/users/:id`) in future milestones.
+     * Routes are tried in registration order. A route matches when the
+     * method matches and the path segments line up, binding any `:name`
+     * or `*name` segments into `Request::params` before the handler runs.
+     * Returns `Response::not_found()` if no matching route is found.
      */
     pub fn dispatch(&self, req: Request) -> Response {
-        // Exact match (method + path).
-        // TODO: Support path parameters like /users/:id
+        self.run_layer(0, req)
+    }
+
+    fn run_layer(&self, index: usize, req: Request) -> Response {
+        match self.layers.get(index) {
+            Some(layer) => {
+                let next = |req: Request| self.run_layer(index + 1, req);
+                layer.handle(req, &next)
+            }
+            None => self.resolve(req),
+        }
+    }
+
+    fn resolve(&self, mut req: Request) -> Response {
         for route in &self.routes {
-            if route.method == req.method && route.path == req.path {
+            if route.method != req.method {
+                continue;
+            }
+
+            if let Some(params) = route.matches(&req.path) {
+                req.params = params;
                 return (route.handler)(req);
             }
         }
@@ -218,3 +386,59 @@ impl Router {
         Response::not_found()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Method, Route, Segment};
+    use crate::response::Response;
+    use std::sync::Arc;
+
+    fn route(method: Method, path: &str) -> Route {
+        Route {
+            method,
+            segments: Segment::parse_all(path),
+            path: path.to_string(),
+            handler: Arc::new(|_req| Response::text("")),
+        }
+    }
+
+    #[test]
+    fn matches_and_binds_a_named_param() {
+        let route = route(Method::GET, "/users/:id");
+        let params = route.matches("/users/42").unwrap();
+        assert_eq!(params.get("id").map(String::as_str), Some("42"));
+    }
+
+    #[test]
+    fn rejects_mismatched_segment_counts() {
+        let route = route(Method::GET, "/users/:id");
+        assert!(route.matches("/users").is_none());
+        assert!(route.matches("/users/42/extra").is_none());
+    }
+
+    #[test]
+    fn enforces_an_inline_regex_constraint() {
+        let route = route(Method::GET, r"/users/:id<\d+>");
+        assert!(route.matches("/users/42").is_some());
+        assert!(route.matches("/users/abc").is_none());
+    }
+
+    #[test]
+    fn captures_a_trailing_wildcard_segment() {
+        let route = route(Method::GET, "/static/*path");
+        let params = route.matches("/static/a/b/c").unwrap();
+        assert_eq!(params.get("path").map(String::as_str), Some("a/b/c"));
+    }
+
+    #[test]
+    fn rejects_a_wildcard_with_nothing_to_capture() {
+        let route = route(Method::GET, "/static/*path");
+        assert!(route.matches("/static").is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid route constraint")]
+    fn panics_on_an_invalid_inline_constraint() {
+        Segment::parse_all("/users/:id<(unterminated>");
+    }
+}
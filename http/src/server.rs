@@ -5,16 +5,28 @@
  * per connection. It's designed for early-stage development and will be replaced
  * with async/hyper-based infrastructure in future milestones.
  */
-use std::io::Read;
-use std::io::Write;
+use std::io::{BufReader, Write};
 use std::net::{TcpListener, TcpStream};
 use std::sync::Arc;
 use std::thread;
+use std::time::Duration;
 
-use crate::http::requests::Request;
+use crate::http::requests::{Request, RequestReadError};
 use crate::http::response::Response;
 use crate::prelude::Router;
 
+/// Default ceiling on a request body's size when a server is built with
+/// `Server::new` and no explicit `max_body_len`.
+const DEFAULT_MAX_BODY_LEN: usize = 10 * 1024 * 1024;
+
+/// Default idle keep-alive timeout: how long a persistent connection may sit
+/// without a new request before the server closes it.
+const DEFAULT_KEEP_ALIVE: Duration = Duration::from_secs(5);
+
+/// Default slow-request timeout: how long a single read may wait once a
+/// request has started arriving.
+const DEFAULT_CLIENT_TIMEOUT: Duration = Duration::from_secs(5);
+
 /**
  * HTTP server that binds to a TCP address and dispatches requests to a Router.
  *
@@ -36,6 +48,9 @@ use crate::prelude::Router;
 pub struct Server {
     address: String,
     router: Arc<Router>,
+    max_body_len: usize,
+    keep_alive: Duration,
+    client_timeout: Duration,
 }
 
 impl Server {
@@ -55,9 +70,50 @@ impl Server {
         Self {
             address: address.to_string(),
             router: Arc::new(router),
+            max_body_len: DEFAULT_MAX_BODY_LEN,
+            keep_alive: DEFAULT_KEEP_ALIVE,
+            client_timeout: DEFAULT_CLIENT_TIMEOUT,
         }
     }
 
+    /**
+     * Sets the maximum number of request body bytes the server will buffer
+     * in memory for a single request. Requests whose `Content-Length` (or
+     * decoded chunked size) exceeds this limit are rejected with
+     * `413 Payload Too Large`.
+     *
+     * Defaults to 10 MiB.
+     */
+    pub fn max_body_len(mut self, limit: usize) -> Self {
+        self.max_body_len = limit;
+        self
+    }
+
+    /**
+     * Sets how long a persistent (keep-alive) connection may sit idle,
+     * waiting for the next request, before the server closes it.
+     *
+     * Defaults to 5 seconds.
+     */
+    pub fn keep_alive(mut self, timeout: Duration) -> Self {
+        self.keep_alive = timeout;
+        self
+    }
+
+    /**
+     * Sets the read timeout used once a request has started arriving: for
+     * each read of the rest of its headers and of its body. A client that
+     * sends nothing for this long receives a `408 Request Timeout` and the
+     * connection is closed. The timeout bounds each read, not the total
+     * time a request takes to arrive.
+     *
+     * Defaults to 5 seconds.
+     */
+    pub fn client_timeout(mut self, timeout: Duration) -> Self {
+        self.client_timeout = timeout;
+        self
+    }
+
     /**
      * Starts the HTTP server and listens for incoming connections.
      *
@@ -81,8 +137,11 @@ impl Server {
             match stream {
                 Ok(stream) => {
                     let router = Arc::clone(&self.router);
+                    let max_body_len = self.max_body_len;
+                    let keep_alive = self.keep_alive;
+                    let client_timeout = self.client_timeout;
                     thread::spawn(move || {
-                        handle_client(stream, router);
+                        handle_client(stream, router, max_body_len, keep_alive, client_timeout);
                     });
                 }
                 Err(err) => {
@@ -94,28 +153,55 @@ impl Server {
 }
 
 /**
- * Handles a single client connection by reading the request, dispatching to the router,
- * and writing the response.
+ * Handles a client connection, looping to serve one request after another on
+ * the same `TcpStream` until the connection should close.
  *
  * # Special Handling
  * - HEAD requests automatically have their response bodies stripped (per HTTP spec)
  * - Malformed requests receive a 400 Bad Request response
+ * - Bodies larger than `max_body_len` receive a 413 Payload Too Large response
+ * - A request that stalls for longer than `client_timeout` in a single read,
+ *   once it has started arriving, receives a 408 Request Timeout response
+ * - An idle connection that exceeds `keep_alive` without starting a new
+ *   request is closed without a response
  * - Read/write errors are logged to stderr but don't crash the server
  *
- * # Buffer Size
- * Currently reads up to 4KB per request. Larger requests will be truncated.
- * Future versions will support chunked transfer encoding and streaming.
+ * # Framing
+ * The request head (request line + headers) is read first, then the body is
+ * read according to `Content-Length` or chunked `Transfer-Encoding` framing,
+ * so bodies of any size (and binary bodies) survive intact.
+ *
+ * # Keep-Alive
+ * By HTTP/1.0 rules the connection closes by default unless the client sends
+ * `Connection: keep-alive`; by HTTP/1.1 rules it stays open by default unless
+ * the client sends `Connection: close`. The response echoes back whichever
+ * behavior was selected.
  */
-fn handle_client(mut stream: TcpStream, router: Arc<Router>) {
-    let mut buffer = [0u8; 4096];
-
-    match stream.read(&mut buffer) {
-        Ok(bytes_read) if bytes_read > 0 => {
-            let raw = String::from_utf8_lossy(&buffer[..bytes_read]);
+fn handle_client(
+    stream: TcpStream,
+    router: Arc<Router>,
+    max_body_len: usize,
+    keep_alive: Duration,
+    client_timeout: Duration,
+) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(err) => {
+            eprintln!("Failed to clone connection: {}", err);
+            return;
+        }
+    };
+    let mut reader = BufReader::new(stream);
 
-            if let Some(request) = Request::from_raw(&raw) {
+    loop {
+        match Request::from_stream(&mut reader, max_body_len, keep_alive, client_timeout) {
+            Ok(request) => {
                 let is_head = request.method == crate::http::requests::Method::HEAD;
-                let response = router.dispatch(request);
+                let keep_connection_alive = wants_keep_alive(&request);
+                let response = router.dispatch(request).with_header(
+                    "Connection",
+                    if keep_connection_alive { "keep-alive" } else { "close" },
+                );
 
                 // For HEAD requests, send headers only (no body)
                 let response_bytes = if is_head {
@@ -124,17 +210,50 @@ fn handle_client(mut stream: TcpStream, router: Arc<Router>) {
                     response.to_http_bytes()
                 };
 
-                if let Err(err) = stream.write_all(&response_bytes) {
+                if let Err(err) = writer.write_all(&response_bytes) {
                     eprintln!("Failed to write response: {}", err);
+                    return;
+                }
+
+                if !keep_connection_alive {
+                    return;
                 }
-            } else {
-                // Malformed request
+            }
+            Err(RequestReadError::ConnectionClosed) | Err(RequestReadError::IdleTimeout) => {
+                return;
+            }
+            Err(RequestReadError::Malformed) => {
                 let response_bytes = Response::new(400, "Bad Request").to_http_bytes();
-                let _ = stream.write_all(&response_bytes);
+                let _ = writer.write_all(&response_bytes);
+                return;
+            }
+            Err(RequestReadError::PayloadTooLarge) => {
+                let response_bytes = Response::new(413, "Payload Too Large").to_http_bytes();
+                let _ = writer.write_all(&response_bytes);
+                return;
+            }
+            Err(RequestReadError::SlowRequestTimeout) => {
+                let response_bytes = Response::new(408, "Request Timeout").to_http_bytes();
+                let _ = writer.write_all(&response_bytes);
+                return;
+            }
+            Err(RequestReadError::Io(err)) => {
+                eprintln!("Failed to read request: {}", err);
+                return;
             }
         }
-        _ => {
-            // Ignore empty/failed reads
-        }
+    }
+}
+
+/// Determines whether the connection this request arrived on should be kept
+/// alive, honoring an explicit `Connection` header first and otherwise
+/// falling back to the protocol version's default (HTTP/1.0 closes, HTTP/1.1
+/// keeps alive). `Request::header` lookups are case-insensitive, so
+/// `connection: close` is honored the same as `Connection: close`.
+fn wants_keep_alive(request: &Request) -> bool {
+    match request.header("Connection") {
+        Some(value) if value.eq_ignore_ascii_case("close") => false,
+        Some(value) if value.eq_ignore_ascii_case("keep-alive") => true,
+        _ => request.version.eq_ignore_ascii_case("HTTP/1.1"),
     }
 }
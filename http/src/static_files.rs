@@ -0,0 +1,89 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::{
+    httpdate,
+    requests::Request,
+    response::{self, Response},
+};
+
+/**
+ * Builds a handler that serves files out of `root`, honoring conditional
+ * requests (`If-None-Match` / `If-Modified-Since`) and guarding against path
+ * traversal. Mounted via `Router::static_dir`, which registers this behind a
+ * `*path` wildcard route so the requested sub-path arrives as a route
+ * parameter.
+ */
+pub fn serve_dir(root: impl Into<PathBuf>) -> impl Fn(Request) -> Response + Send + Sync + 'static {
+    let root = root.into();
+    move |req: Request| serve(&req, &root)
+}
+
+fn serve(req: &Request, root: &Path) -> Response {
+    let Ok(canonical_root) = root.canonicalize() else {
+        return Response::not_found();
+    };
+
+    let requested = req.param("path").unwrap_or("");
+    let canonical = match root.join(requested).canonicalize() {
+        Ok(path) => path,
+        Err(_) => return Response::not_found(),
+    };
+
+    if !canonical.starts_with(&canonical_root) {
+        return Response::new(403, "Forbidden");
+    }
+
+    respond(req, &canonical).unwrap_or_else(|_| Response::not_found())
+}
+
+/// Serves `path`, answering `304 Not Modified` when the request's
+/// conditional headers indicate the client's cached copy is still current.
+/// `If-None-Match` takes priority over `If-Modified-Since` when both are
+/// present, per HTTP semantics.
+fn respond(req: &Request, path: &Path) -> io::Result<Response> {
+    let metadata = fs::metadata(path)?;
+    if metadata.is_dir() {
+        return Ok(Response::not_found());
+    }
+
+    let etag = response::etag_for(&metadata);
+    let modified = metadata.modified()?;
+
+    let not_modified = if let Some(if_none_match) = req.header("If-None-Match") {
+        matches_etag(if_none_match, &etag)
+    } else if let Some(if_modified_since) = req.header("If-Modified-Since") {
+        httpdate::parse(if_modified_since).is_some_and(|since| !is_modified_after(modified, since))
+    } else {
+        false
+    };
+
+    if not_modified {
+        return Ok(Response::new(304, "")
+            .with_header("ETag", &etag)
+            .with_header("Last-Modified", &httpdate::format(modified)));
+    }
+
+    Response::file(path)
+}
+
+/// Compares at one-second resolution, matching HTTP date precision.
+fn is_modified_after(modified: SystemTime, since: SystemTime) -> bool {
+    let as_secs = |time: SystemTime| time.duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0);
+    as_secs(modified) > as_secs(since)
+}
+
+/// Checks an `If-None-Match` header (possibly a comma-separated list, or the
+/// literal `*`) against an ETag, ignoring the `W/` weak-validator prefix.
+fn matches_etag(header_value: &str, etag: &str) -> bool {
+    header_value
+        .split(',')
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || strip_weak(candidate) == strip_weak(etag))
+}
+
+fn strip_weak(value: &str) -> &str {
+    value.strip_prefix("W/").unwrap_or(value)
+}